@@ -5,7 +5,7 @@
 //! use protobuf_ast_parser::ast::{Field, FieldModifier, Message, MessageEntry, RootEntry};
 //!
 //! let field = Field::new(Some(FieldModifier::Optional), "string", "name", 1, vec![]);
-//! let message = Message::new("User", vec![MessageEntry::Field(field)]);
+//! let message = Message::new("User", vec![MessageEntry::from(field)]);
 //! let file = vec![RootEntry::from(message)];
 //! assert_eq!(file.len(), 1);
 //! ```
@@ -16,6 +16,148 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 
+/// A byte-offset range into the original source text.
+///
+/// Spans are half-open (`start..end`) and are always within the bounds of the
+/// source they were produced from.
+///
+/// # Examples
+/// ```rust
+/// use protobuf_ast_parser::ast::Span;
+///
+/// let source = "line one\nline two";
+/// let span = Span::new(9, 13);
+/// assert_eq!(span.resolve(source), (2, 1));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Resolves this span's start offset against `source` to a 1-based
+    /// `(line, column)` pair, counting newlines up to the offset.
+    pub fn resolve(&self, source: &str) -> (usize, usize) {
+        let mut line = 1;
+        let mut column = 1;
+
+        for byte in source[..self.start.min(source.len())].bytes() {
+            if byte == b'\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+
+        (line, column)
+    }
+}
+
+impl IntoOwned for Span {
+    type Owned = Self;
+
+    fn into_owned(self) -> Self::Owned {
+        self
+    }
+}
+
+/// A value paired with the [`Span`] it was parsed from.
+///
+/// `Spanned<T>` transparently `Deref`s to `T` so existing field accesses on
+/// the wrapped value keep working. Equality deliberately ignores the span:
+/// two `Spanned<T>` are equal iff their values are, which keeps the large
+/// `assert_eq!(ast, target_ast)` fixtures in `tests.rs` green without them
+/// needing to know about real source positions.
+///
+/// This wrapper is for leaf values that have nowhere of their own to keep a
+/// span (`Cow<str>`, `i64`, ...), e.g. `Field.ident`/`Field.index`. AST nodes
+/// that already own a struct (`Message`, `Field`, `Option`, ...) instead
+/// carry their span inline as a `span: Span` field with a matching
+/// span-ignoring `PartialEq`, so there is exactly one place each kind of
+/// value keeps its span.
+///
+/// # Examples
+/// ```rust
+/// use protobuf_ast_parser::ast::{Span, Spanned};
+///
+/// let spanned = Spanned::new(42, Span::new(0, 2));
+/// assert_eq!(*spanned, 42);
+/// assert_eq!(spanned, Spanned::new(42, Span::new(5, 9)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    pub fn new(value: T, span: Span) -> Self {
+        Self { value, span }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Deref for Spanned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Spanned<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Spanned<T> {
+    type Owned = Spanned<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        Spanned {
+            value: self.value.into_owned(),
+            span: self.span,
+        }
+    }
+}
+
+/// Implements a span-ignoring `PartialEq` for an AST node: two nodes are
+/// equal iff every field but `span` matches. Mirrors [`Spanned<T>`]'s
+/// equality, so the large fixture-based `assert_eq!(ast, target_ast)` tests
+/// in `tests.rs` stay independent of real source positions, without every
+/// spanned node hand-rolling the same comparison.
+macro_rules! impl_span_ignoring_eq {
+    ($ty:ident<$lt:lifetime> { $($field:ident),+ $(,)? }) => {
+        impl<$lt> PartialEq for $ty<$lt> {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&+
+            }
+        }
+    };
+}
+
 /// Represents a reserved or extensions range in `.proto` syntax.
 ///
 /// # Examples
@@ -25,6 +167,7 @@ use std::ops::{Deref, DerefMut};
 /// let finite = Range::from(1..5);
 /// let open_ended = Range::from(10..);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Range {
     Default(std::ops::Range<i64>),
@@ -61,11 +204,14 @@ impl From<std::ops::RangeFrom<i64>> for Range {
 /// let map: Map = [(Cow::from("enabled"), MapValue::from(true))].into();
 /// let value = MapValue::from(map);
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum MapValue<'a> {
     Boolean(bool),
     Integer(i64),
+    #[cfg_attr(feature = "serde", serde(borrow))]
     Ident(Cow<'a, str>),
+    #[cfg_attr(feature = "serde", serde(borrow))]
     String(Cow<'a, str>),
     Map(Map<'a>),
 }
@@ -116,10 +262,13 @@ impl<'a> FromBorrowedIter<'a> for Map<'a> {
 /// let option = Option::new("deprecated", MapValue::from(true));
 /// assert_eq!(option.key, "deprecated");
 /// ```
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Option<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub key: Cow<'a, str>,
     pub value: MapValue<'a>,
+    pub span: Span,
 }
 
 impl<'a> Option<'a> {
@@ -127,16 +276,23 @@ impl<'a> Option<'a> {
         Self {
             key: Cow::from(key),
             value,
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(Option<'a> { key, value });
+
 /// A parsed comment with both raw source and trimmed text.
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Comment<'a> {
     pub r#type: CommentType,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub source: Cow<'a, str>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub text: Cow<'a, str>,
+    pub span: Span,
 }
 
 impl<'a> Comment<'a> {
@@ -145,6 +301,7 @@ impl<'a> Comment<'a> {
             r#type,
             text: Cow::from(text),
             source: Cow::from(source),
+            span: Span::default(),
         }
     }
 
@@ -153,6 +310,7 @@ impl<'a> Comment<'a> {
             r#type: CommentType::SingleLine,
             text: Cow::from(source[2..].trim()),
             source: Cow::from(source),
+            span: Span::default(),
         }
     }
 
@@ -161,17 +319,132 @@ impl<'a> Comment<'a> {
             r#type: CommentType::MultiLine,
             text: Cow::from(source[2..source.len() - 2].trim()),
             source: Cow::from(source),
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(Comment<'a> { r#type, source, text });
+
 /// Comment type markers for single-line (`//`) and multi-line (`/* */`) comments.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum CommentType {
     SingleLine,
     MultiLine,
 }
 
+/// A declaration together with the comments immediately surrounding it.
+///
+/// Mirrors [`Spanned<T>`]: `Documented<T>` transparently `Deref`s to `T`, and
+/// wrapping a bare value (via `From`) attaches no comments, so existing call
+/// sites that build a `Field`/`Message`/etc. directly keep working. During
+/// parsing, a contiguous run of comments directly preceding a declaration is
+/// consumed into `leading`, and a same-line trailing comment into `trailing`;
+/// comments that aren't adjacent to any declaration stay as standalone
+/// `Comment` entries.
+///
+/// # Examples
+/// ```rust
+/// use protobuf_ast_parser::ast::{Comment, Documented, Message};
+///
+/// let message = Documented::new(Message::empty("User"), vec![Comment::single_line("// a user")], None);
+/// assert_eq!(message.ident, "User");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Documented<'a, T> {
+    pub leading: Vec<Comment<'a>>,
+    pub trailing: std::option::Option<Comment<'a>>,
+    pub inner: T,
+    /// Byte range of the raw whitespace run directly preceding this
+    /// declaration (after any `leading` comments), populated only by a
+    /// lossless parse; `None` otherwise. Stored as a [`Span`] into the
+    /// original source rather than duplicating the text.
+    pub leading_trivia: std::option::Option<Span>,
+    /// Byte range of the raw whitespace run directly following this
+    /// declaration's `trailing` comment (or the declaration itself, if it has
+    /// none), populated only by a lossless parse; `None` otherwise.
+    pub trailing_trivia: std::option::Option<Span>,
+}
+
+impl<'a, T> Documented<'a, T> {
+    pub fn new(
+        inner: T,
+        leading: Vec<Comment<'a>>,
+        trailing: std::option::Option<Comment<'a>>,
+    ) -> Self {
+        Self {
+            inner,
+            leading,
+            trailing,
+            leading_trivia: None,
+            trailing_trivia: None,
+        }
+    }
+
+    pub fn undocumented(inner: T) -> Self {
+        Self {
+            inner,
+            leading: vec![],
+            trailing: None,
+            leading_trivia: None,
+            trailing_trivia: None,
+        }
+    }
+
+    /// Attaches lossless whitespace trivia to an already-built [`Documented`],
+    /// as produced by a lossless parse.
+    pub fn with_trivia(
+        mut self,
+        leading_trivia: std::option::Option<Span>,
+        trailing_trivia: std::option::Option<Span>,
+    ) -> Self {
+        self.leading_trivia = leading_trivia;
+        self.trailing_trivia = trailing_trivia;
+        self
+    }
+
+    /// Returns the text of each leading doc comment, in source order.
+    pub fn doc_comments(&self) -> impl Iterator<Item = &str> {
+        self.leading.iter().map(|comment| comment.text.as_ref())
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq for Documented<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.leading == other.leading && self.trailing == other.trailing
+    }
+}
+
+impl<'a, T> Deref for Documented<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a, T> DerefMut for Documented<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a, T: IntoOwned> IntoOwned for Documented<'a, T> {
+    type Owned = Documented<'static, T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        Documented {
+            leading: self.leading.into_owned(),
+            trailing: self.trailing.into_owned(),
+            inner: self.inner.into_owned(),
+            leading_trivia: self.leading_trivia,
+            trailing_trivia: self.trailing_trivia,
+        }
+    }
+}
+
 /// Top-level entries in a `.proto` file.
 ///
 /// # Examples
@@ -180,17 +453,21 @@ pub enum CommentType {
 ///
 /// let entry = RootEntry::from(Comment::single_line("// hi"));
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum RootEntry<'a> {
     Comment(Comment<'a>),
+    #[cfg_attr(feature = "serde", serde(borrow))]
     Syntax(Cow<'a, str>),
+    #[cfg_attr(feature = "serde", serde(borrow))]
     Package(Cow<'a, str>),
+    #[cfg_attr(feature = "serde", serde(borrow))]
     Import(Cow<'a, str>),
     Option(Option<'a>),
     Service(Service<'a>),
-    Message(Message<'a>),
+    Message(Documented<'a, Message<'a>>),
     Extend(Extend<'a>),
-    Enum(Enum<'a>),
+    Enum(Documented<'a, Enum<'a>>),
 }
 
 impl<'a> From<Comment<'a>> for RootEntry<'a> {
@@ -213,6 +490,12 @@ impl<'a> From<Service<'a>> for RootEntry<'a> {
 
 impl<'a> From<Message<'a>> for RootEntry<'a> {
     fn from(message: Message<'a>) -> Self {
+        Self::Message(Documented::undocumented(message))
+    }
+}
+
+impl<'a> From<Documented<'a, Message<'a>>> for RootEntry<'a> {
+    fn from(message: Documented<'a, Message<'a>>) -> Self {
         Self::Message(message)
     }
 }
@@ -225,6 +508,12 @@ impl<'a> From<Extend<'a>> for RootEntry<'a> {
 
 impl<'a> From<Enum<'a>> for RootEntry<'a> {
     fn from(r#enum: Enum<'a>) -> Self {
+        Self::Enum(Documented::undocumented(r#enum))
+    }
+}
+
+impl<'a> From<Documented<'a, Enum<'a>>> for RootEntry<'a> {
+    fn from(r#enum: Documented<'a, Enum<'a>>) -> Self {
         Self::Enum(r#enum)
     }
 }
@@ -233,10 +522,13 @@ impl<'a> From<Enum<'a>> for RootEntry<'a> {
 pub type Root<'a> = Vec<RootEntry<'a>>;
 
 /// Service definition with its RPC entries.
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Service<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub ident: Cow<'a, str>,
     pub entries: Vec<ServiceEntry<'a>>,
+    pub span: Span,
 }
 
 impl<'a> Service<'a> {
@@ -244,17 +536,21 @@ impl<'a> Service<'a> {
         Self {
             ident: Cow::from(ident),
             entries,
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(Service<'a> { ident, entries });
+
 /// Entries that can appear inside a `service` block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum ServiceEntry<'a> {
     Comment(Comment<'a>),
     Option(Option<'a>),
 
-    Rpc(Rpc<'a>),
+    Rpc(Documented<'a, Rpc<'a>>),
 }
 
 impl<'a> From<Comment<'a>> for ServiceEntry<'a> {
@@ -271,33 +567,47 @@ impl<'a> From<Option<'a>> for ServiceEntry<'a> {
 
 impl<'a> From<Rpc<'a>> for ServiceEntry<'a> {
     fn from(rpc: Rpc<'a>) -> Self {
+        ServiceEntry::Rpc(Documented::undocumented(rpc))
+    }
+}
+
+impl<'a> From<Documented<'a, Rpc<'a>>> for ServiceEntry<'a> {
+    fn from(rpc: Documented<'a, Rpc<'a>>) -> Self {
         ServiceEntry::Rpc(rpc)
     }
 }
 
 /// RPC definition inside a `service`.
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Rpc<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub ident: Cow<'a, str>,
 
-    pub request: Cow<'a, str>,
-    pub reply: Cow<'a, str>,
+    pub request: FieldType<'a>,
+    pub reply: FieldType<'a>,
 
     pub stream: RpcStream,
+
+    pub span: Span,
 }
 
 impl<'a> Rpc<'a> {
     pub fn new(ident: &'a str, request: &'a str, reply: &'a str, stream: RpcStream) -> Self {
         Self {
             ident: Cow::from(ident),
-            request: Cow::from(request),
-            reply: Cow::from(reply),
+            request: FieldType::parse(request),
+            reply: FieldType::parse(reply),
             stream,
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(Rpc<'a> { ident, request, reply, stream });
+
 /// Streaming mode for an RPC definition.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum RpcStream {
     None,
@@ -318,10 +628,13 @@ impl RpcStream {
 }
 
 /// Message definition with nested entries.
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Message<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub ident: Cow<'a, str>,
     pub entries: Vec<MessageEntry<'a>>,
+    pub span: Span,
 }
 
 impl<'a> Message<'a> {
@@ -329,6 +642,7 @@ impl<'a> Message<'a> {
         Self {
             ident: Cow::from(ident),
             entries,
+            span: Span::default(),
         }
     }
 
@@ -336,10 +650,14 @@ impl<'a> Message<'a> {
         Self {
             ident: Cow::from(ident),
             entries: vec![],
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(Message<'a> { ident, entries });
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub struct ReservedIndices(Vec<Range>);
 
@@ -369,8 +687,9 @@ impl DerefMut for ReservedIndices {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
-pub struct ReservedIdents<'a>(Vec<Cow<'a, str>>);
+pub struct ReservedIdents<'a>(#[cfg_attr(feature = "serde", serde(borrow))] Vec<Cow<'a, str>>);
 
 impl<'a> From<Vec<&'a str>> for ReservedIdents<'a> {
     fn from(value: Vec<&'a str>) -> Self {
@@ -404,6 +723,7 @@ impl<'a> DerefMut for ReservedIdents<'a> {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub struct Extensions(Vec<Range>);
 
@@ -434,16 +754,17 @@ impl DerefMut for Extensions {
 }
 
 /// Entries that can appear inside a `message` block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum MessageEntry<'a> {
     Comment(Comment<'a>),
     Option(Option<'a>),
 
-    Field(Field<'a>),
-    OneOf(OneOf<'a>),
-    Message(Message<'a>),
+    Field(Documented<'a, Field<'a>>),
+    OneOf(Documented<'a, OneOf<'a>>),
+    Message(Documented<'a, Message<'a>>),
     Extend(Extend<'a>),
-    Enum(Enum<'a>),
+    Enum(Documented<'a, Enum<'a>>),
 
     ReservedIndices(ReservedIndices),
     ReservedIdents(ReservedIdents<'a>),
@@ -465,18 +786,36 @@ impl<'a> From<Option<'a>> for MessageEntry<'a> {
 
 impl<'a> From<Field<'a>> for MessageEntry<'a> {
     fn from(field: Field<'a>) -> Self {
+        Self::Field(Documented::undocumented(field))
+    }
+}
+
+impl<'a> From<Documented<'a, Field<'a>>> for MessageEntry<'a> {
+    fn from(field: Documented<'a, Field<'a>>) -> Self {
         Self::Field(field)
     }
 }
 
 impl<'a> From<OneOf<'a>> for MessageEntry<'a> {
     fn from(one_of: OneOf<'a>) -> Self {
+        Self::OneOf(Documented::undocumented(one_of))
+    }
+}
+
+impl<'a> From<Documented<'a, OneOf<'a>>> for MessageEntry<'a> {
+    fn from(one_of: Documented<'a, OneOf<'a>>) -> Self {
         Self::OneOf(one_of)
     }
 }
 
 impl<'a> From<Message<'a>> for MessageEntry<'a> {
     fn from(message: Message<'a>) -> Self {
+        Self::Message(Documented::undocumented(message))
+    }
+}
+
+impl<'a> From<Documented<'a, Message<'a>>> for MessageEntry<'a> {
+    fn from(message: Documented<'a, Message<'a>>) -> Self {
         Self::Message(message)
     }
 }
@@ -489,6 +828,12 @@ impl<'a> From<Extend<'a>> for MessageEntry<'a> {
 
 impl<'a> From<Enum<'a>> for MessageEntry<'a> {
     fn from(r#enum: Enum<'a>) -> Self {
+        Self::Enum(Documented::undocumented(r#enum))
+    }
+}
+
+impl<'a> From<Documented<'a, Enum<'a>>> for MessageEntry<'a> {
+    fn from(r#enum: Documented<'a, Enum<'a>>) -> Self {
         Self::Enum(r#enum)
     }
 }
@@ -511,6 +856,90 @@ impl<'a> From<Extensions> for MessageEntry<'a> {
     }
 }
 
+/// The type of a field, distinguishing protobuf scalars and `map<K, V>`
+/// from a plain message/enum name reference.
+///
+/// # Examples
+/// ```rust
+/// use protobuf_ast_parser::ast::FieldType;
+///
+/// assert_eq!(FieldType::parse("int32"), FieldType::Int32);
+/// assert_eq!(
+///     FieldType::parse("map<string, int32>"),
+///     FieldType::Map {
+///         key: Box::new(FieldType::String),
+///         value: Box::new(FieldType::Int32),
+///     }
+/// );
+/// assert_eq!(FieldType::parse("my.pkg.User"), FieldType::Named("my.pkg.User".into()));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, IntoOwned)]
+pub enum FieldType<'a> {
+    Double,
+    Float,
+    Int32,
+    Int64,
+    Uint32,
+    Uint64,
+    Sint32,
+    Sint64,
+    Fixed32,
+    Fixed64,
+    Sfixed32,
+    Sfixed64,
+    Bool,
+    String,
+    Bytes,
+
+    Map {
+        key: Box<FieldType<'a>>,
+        value: Box<FieldType<'a>>,
+    },
+
+    /// A message or enum name, possibly fully-qualified with a leading `.`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Named(Cow<'a, str>),
+}
+
+impl<'a> FieldType<'a> {
+    /// Parses a protobuf type name, recognizing every scalar keyword and
+    /// `map<key, value>` syntax, falling back to [`FieldType::Named`] for
+    /// message/enum references.
+    pub fn parse(r#type: &'a str) -> Self {
+        let r#type = r#type.trim();
+
+        match r#type {
+            "double" => Self::Double,
+            "float" => Self::Float,
+            "int32" => Self::Int32,
+            "int64" => Self::Int64,
+            "uint32" => Self::Uint32,
+            "uint64" => Self::Uint64,
+            "sint32" => Self::Sint32,
+            "sint64" => Self::Sint64,
+            "fixed32" => Self::Fixed32,
+            "fixed64" => Self::Fixed64,
+            "sfixed32" => Self::Sfixed32,
+            "sfixed64" => Self::Sfixed64,
+            "bool" => Self::Bool,
+            "string" => Self::String,
+            "bytes" => Self::Bytes,
+            _ => Self::parse_map(r#type).unwrap_or_else(|| Self::Named(Cow::from(r#type))),
+        }
+    }
+
+    fn parse_map(r#type: &'a str) -> std::option::Option<Self> {
+        let inner = r#type.strip_prefix("map<")?.strip_suffix('>')?;
+        let (key, value) = inner.split_once(',')?;
+
+        Some(Self::Map {
+            key: Box::new(Self::parse(key.trim())),
+            value: Box::new(Self::parse(value.trim())),
+        })
+    }
+}
+
 /// Field definition inside a message, oneof, or extend block.
 ///
 /// # Examples
@@ -518,15 +947,18 @@ impl<'a> From<Extensions> for MessageEntry<'a> {
 /// use protobuf_ast_parser::ast::{Field, FieldModifier};
 ///
 /// let field = Field::new(Some(FieldModifier::Optional), "string", "name", 1, vec![]);
-/// assert_eq!(field.index, 1);
+/// assert_eq!(*field.index, 1);
 /// ```
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Field<'a> {
     pub modifier: std::option::Option<FieldModifier>,
-    pub r#type: Cow<'a, str>,
-    pub ident: Cow<'a, str>,
-    pub index: i64,
+    pub r#type: FieldType<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ident: Spanned<Cow<'a, str>>,
+    pub index: Spanned<i64>,
     pub options: Vec<Option<'a>>,
+    pub span: Span,
 }
 
 impl<'a> Field<'a> {
@@ -539,19 +971,45 @@ impl<'a> Field<'a> {
     ) -> Self {
         Self {
             modifier,
-            r#type: Cow::from(r#type),
-            ident: Cow::from(ident),
-            index,
+            r#type: FieldType::parse(r#type),
+            ident: Spanned::new(Cow::from(ident), Span::default()),
+            index: Spanned::new(index, Span::default()),
+            options,
+            span: Span::default(),
+        }
+    }
+
+    /// Like [`Field::new`], but for the parser to attach the real source
+    /// spans of the whole field, plus the identifier and index tokens.
+    pub fn with_spans(
+        modifier: std::option::Option<FieldModifier>,
+        r#type: &'a str,
+        ident: (&'a str, Span),
+        index: (i64, Span),
+        options: Vec<Option<'a>>,
+        span: Span,
+    ) -> Self {
+        Self {
+            modifier,
+            r#type: FieldType::parse(r#type),
+            ident: Spanned::new(Cow::from(ident.0), ident.1),
+            index: Spanned::new(index.0, index.1),
             options,
+            span,
         }
     }
 }
 
+impl_span_ignoring_eq!(Field<'a> { modifier, r#type, ident, index, options });
+
 /// `oneof` definition inside a message.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub struct OneOf<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub ident: Cow<'a, str>,
     pub entries: Vec<OneOfEntry<'a>>,
+    pub span: Span,
 }
 
 impl<'a> OneOf<'a> {
@@ -559,17 +1017,21 @@ impl<'a> OneOf<'a> {
         Self {
             ident: Cow::from(ident),
             entries,
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(OneOf<'a> { ident, entries });
+
 /// Entries that can appear inside a `oneof` block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum OneOfEntry<'a> {
     Comment(Comment<'a>),
     Option(Option<'a>),
 
-    Field(Field<'a>),
+    Field(Documented<'a, Field<'a>>),
 }
 
 impl<'a> From<Comment<'a>> for OneOfEntry<'a> {
@@ -586,11 +1048,18 @@ impl<'a> From<Option<'a>> for OneOfEntry<'a> {
 
 impl<'a> From<Field<'a>> for OneOfEntry<'a> {
     fn from(field: Field<'a>) -> Self {
+        Self::Field(Documented::undocumented(field))
+    }
+}
+
+impl<'a> From<Documented<'a, Field<'a>>> for OneOfEntry<'a> {
+    fn from(field: Documented<'a, Field<'a>>) -> Self {
         Self::Field(field)
     }
 }
 
 /// Field modifier keywords.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum FieldModifier {
     Optional,
@@ -599,10 +1068,13 @@ pub enum FieldModifier {
 }
 
 /// Extend block definition.
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Extend<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub r#type: Cow<'a, str>,
     pub entries: Vec<ExtendEntry<'a>>,
+    pub span: Span,
 }
 
 impl<'a> Extend<'a> {
@@ -610,15 +1082,19 @@ impl<'a> Extend<'a> {
         Self {
             r#type: Cow::from(r#type),
             entries,
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(Extend<'a> { r#type, entries });
+
 /// Entries that can appear inside an `extend` block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum ExtendEntry<'a> {
     Comment(Comment<'a>),
-    Field(Field<'a>),
+    Field(Documented<'a, Field<'a>>),
 }
 
 impl<'a> From<Comment<'a>> for ExtendEntry<'a> {
@@ -629,15 +1105,24 @@ impl<'a> From<Comment<'a>> for ExtendEntry<'a> {
 
 impl<'a> From<Field<'a>> for ExtendEntry<'a> {
     fn from(field: Field<'a>) -> Self {
+        Self::Field(Documented::undocumented(field))
+    }
+}
+
+impl<'a> From<Documented<'a, Field<'a>>> for ExtendEntry<'a> {
+    fn from(field: Documented<'a, Field<'a>>) -> Self {
         Self::Field(field)
     }
 }
 
 /// Enum definition.
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct Enum<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub ident: Cow<'a, str>,
     pub entries: Vec<EnumEntry<'a>>,
+    pub span: Span,
 }
 
 impl<'a> Enum<'a> {
@@ -645,16 +1130,20 @@ impl<'a> Enum<'a> {
         Self {
             ident: Cow::from(ident),
             entries,
+            span: Span::default(),
         }
     }
 }
 
+impl_span_ignoring_eq!(Enum<'a> { ident, entries });
+
 /// Entries that can appear inside an `enum` block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, IntoOwned)]
 pub enum EnumEntry<'a> {
     Comment(Comment<'a>),
     Option(Option<'a>),
-    Variant(EnumVariant<'a>),
+    Variant(Documented<'a, EnumVariant<'a>>),
 }
 
 impl<'a> From<Comment<'a>> for EnumEntry<'a> {
@@ -671,6 +1160,12 @@ impl<'a> From<Option<'a>> for EnumEntry<'a> {
 
 impl<'a> From<EnumVariant<'a>> for EnumEntry<'a> {
     fn from(value: EnumVariant<'a>) -> Self {
+        Self::Variant(Documented::undocumented(value))
+    }
+}
+
+impl<'a> From<Documented<'a, EnumVariant<'a>>> for EnumEntry<'a> {
+    fn from(value: Documented<'a, EnumVariant<'a>>) -> Self {
         Self::Variant(value)
     }
 }
@@ -684,11 +1179,14 @@ impl<'a> From<EnumVariant<'a>> for EnumEntry<'a> {
 /// let variant = EnumVariant::new("FIRST", 1, vec![]);
 /// assert_eq!(variant.value, 1);
 /// ```
-#[derive(Debug, Clone, PartialEq, IntoOwned)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, IntoOwned)]
 pub struct EnumVariant<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub ident: Cow<'a, str>,
     pub value: i64,
     pub options: Vec<Option<'a>>,
+    pub span: Span,
 }
 
 impl<'a> EnumVariant<'a> {
@@ -697,6 +1195,9 @@ impl<'a> EnumVariant<'a> {
             ident: Cow::from(ident),
             value,
             options,
+            span: Span::default(),
         }
     }
 }
+
+impl_span_ignoring_eq!(EnumVariant<'a> { ident, value, options });
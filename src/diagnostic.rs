@@ -0,0 +1,138 @@
+//! Human-friendly rendering of parse errors, in the style of `ariadne`/`chumsky`:
+//! a `file:line:column` prefix, the offending source line, and a caret run
+//! underlining the exact span, so integrators (editors, compiler frontends)
+//! can print a [`Diagnostic`] directly instead of the raw
+//! [`lalrpop_util::ParseError`].
+//!
+//! # Examples
+//! ```rust
+//! use protobuf_ast_parser::{diagnostic::render_diagnostic, parse};
+//!
+//! let source = "message 1Invalid {}";
+//! let error = parse(source).unwrap_err();
+//! println!("{}", render_diagnostic("input.proto", source, &error));
+//! ```
+
+use crate::ast::Span;
+use crate::lexer::{LexicalError, Token};
+use lalrpop_util::ParseError;
+use std::fmt;
+
+/// A single, user-facing parse diagnostic: a byte span plus a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub span: Span,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// Renders a [`lalrpop_util::ParseError`] produced by [`crate::parse`] into a
+/// multi-line human-readable report: a `name:line:column` prefix, the
+/// offending source line, and a caret run underlining the exact span.
+pub fn render_diagnostic<'a>(
+    name: &str,
+    source: &'a str,
+    error: &ParseError<usize, Token<'a>, LexicalError<'a>>,
+) -> String {
+    let diagnostic = to_diagnostic(source, error);
+    render(name, source, &diagnostic)
+}
+
+fn to_diagnostic<'a>(
+    source: &'a str,
+    error: &ParseError<usize, Token<'a>, LexicalError<'a>>,
+) -> Diagnostic {
+    match error {
+        ParseError::InvalidToken { location } => Diagnostic {
+            line: 0,
+            column: 0,
+            span: Span::new(*location, location + 1),
+            message: "invalid token".to_string(),
+        },
+        ParseError::UnrecognizedEof { location, expected } => Diagnostic {
+            line: 0,
+            column: 0,
+            span: Span::new(*location, *location),
+            message: format!(
+                "unexpected end of file, expected one of {}",
+                join_expected(expected)
+            ),
+        },
+        ParseError::UnrecognizedToken {
+            token: (start, token, end),
+            expected,
+        } => Diagnostic {
+            line: 0,
+            column: 0,
+            span: Span::new(*start, *end),
+            message: format!(
+                "unexpected token `{token}`, expected one of {}",
+                join_expected(expected)
+            ),
+        },
+        ParseError::ExtraToken {
+            token: (start, token, end),
+        } => Diagnostic {
+            line: 0,
+            column: 0,
+            span: Span::new(*start, *end),
+            message: format!("extra token `{token}`"),
+        },
+        ParseError::User { error } => Diagnostic {
+            line: 0,
+            column: 0,
+            span: Span::new(error.offset, error.offset + 1),
+            message: error.to_string(),
+        },
+    }
+    .with_position(source)
+}
+
+impl Diagnostic {
+    fn with_position(mut self, source: &str) -> Self {
+        let (line, column) = self.span.resolve(source);
+        self.line = line;
+        self.column = column;
+        self
+    }
+}
+
+fn join_expected(expected: &[String]) -> String {
+    expected
+        .iter()
+        .map(|token| format!("`{token}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render(name: &str, source: &str, diagnostic: &Diagnostic) -> String {
+    let line_start = source[..diagnostic.span.start.min(source.len())]
+        .rfind('\n')
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let line_end = source[diagnostic.span.start.min(source.len())..]
+        .find('\n')
+        .map(|index| diagnostic.span.start + index)
+        .unwrap_or(source.len());
+    let line_text = &source[line_start..line_end];
+
+    let underline_start = diagnostic.span.start - line_start;
+    let underline_len = diagnostic.span.end.saturating_sub(diagnostic.span.start).max(1);
+    let underline = format!(
+        "{}{}",
+        " ".repeat(underline_start),
+        "^".repeat(underline_len)
+    );
+
+    format!(
+        "{name}:{}:{}: {}\n{line_text}\n{underline}",
+        diagnostic.line, diagnostic.column, diagnostic.message
+    )
+}
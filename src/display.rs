@@ -0,0 +1,433 @@
+//! Renders a parsed AST back into canonical `.proto` source text.
+//!
+//! # Examples
+//! ```rust
+//! use protobuf_ast_parser::ast::{Message, RootEntry};
+//! use protobuf_ast_parser::display::ToProtoString;
+//!
+//! let root = vec![RootEntry::from(Message::empty("Empty"))];
+//! assert_eq!(root.to_proto_string(), "message Empty {\n}\n");
+//! ```
+
+use crate::ast::{
+    self, Comment, CommentType, Enum, EnumEntry, Extend, ExtendEntry, Field, FieldModifier,
+    FieldType, Map, MapValue, Message, MessageEntry, OneOf, OneOfEntry, Option as AstOption, Range,
+    Rpc, RpcStream, Service, ServiceEntry,
+};
+use std::fmt;
+
+const INDENT: &str = "    ";
+
+/// Converts a parsed `.proto` file ([`ast::Root`]) back into source text.
+pub trait ToProtoString {
+    fn to_proto_string(&self) -> String;
+
+    /// Like [`ToProtoString::to_proto_string`], but starts rendering at
+    /// `indent` levels of nesting instead of the top level. Useful when
+    /// splicing the rendered output into an already-indented document.
+    fn to_proto(&self, indent: usize) -> String;
+}
+
+impl<'a> ToProtoString for ast::Root<'a> {
+    fn to_proto_string(&self) -> String {
+        self.to_proto(0)
+    }
+
+    fn to_proto(&self, indent: usize) -> String {
+        let mut out = String::new();
+
+        for entry in self {
+            write_root_entry(&mut out, entry, indent);
+        }
+
+        out
+    }
+}
+
+impl<'a> fmt::Display for ast::RootEntry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        write_root_entry(&mut out, self, 0);
+        f.write_str(&out)
+    }
+}
+
+fn indent(level: usize) -> String {
+    INDENT.repeat(level)
+}
+
+fn write_root_entry(out: &mut String, entry: &ast::RootEntry, level: usize) {
+    match entry {
+        ast::RootEntry::Comment(comment) => {
+            out.push_str(&indent(level));
+            out.push_str(&comment.source);
+        }
+        ast::RootEntry::Syntax(syntax) => {
+            out.push_str(&indent(level));
+            out.push_str(&format!("syntax = \"{syntax}\";"));
+        }
+        ast::RootEntry::Package(package) => {
+            out.push_str(&indent(level));
+            out.push_str(&format!("package {package};"));
+        }
+        ast::RootEntry::Import(import) => {
+            out.push_str(&indent(level));
+            out.push_str(&format!("import \"{import}\";"));
+        }
+        ast::RootEntry::Option(option) => {
+            out.push_str(&indent(level));
+            out.push_str(&render_option(option));
+        }
+        ast::RootEntry::Service(service) => {
+            out.push_str(&indent(level));
+            write_service(out, service, level);
+        }
+        ast::RootEntry::Message(message) => {
+            write_leading(out, &message.leading, level);
+            out.push_str(&indent(level));
+            write_message(out, &message.inner, level);
+            write_trailing(out, &message.trailing);
+        }
+        ast::RootEntry::Extend(extend) => {
+            out.push_str(&indent(level));
+            write_extend(out, extend, level);
+        }
+        ast::RootEntry::Enum(r#enum) => {
+            write_leading(out, &r#enum.leading, level);
+            out.push_str(&indent(level));
+            write_enum(out, &r#enum.inner, level);
+            write_trailing(out, &r#enum.trailing);
+        }
+    }
+
+    out.push('\n');
+}
+
+fn write_leading(out: &mut String, leading: &[Comment], level: usize) {
+    for comment in leading {
+        out.push_str(&indent(level));
+        out.push_str(&comment.source);
+        out.push('\n');
+    }
+}
+
+fn write_trailing(out: &mut String, trailing: &std::option::Option<Comment>) {
+    if let Some(comment) = trailing {
+        out.push(' ');
+        out.push_str(&comment.source);
+    }
+}
+
+fn write_service(out: &mut String, service: &Service, level: usize) {
+    out.push_str(&format!("service {} {{\n", service.ident));
+
+    for entry in &service.entries {
+        match entry {
+            ServiceEntry::Comment(comment) => {
+                out.push_str(&indent(level + 1));
+                out.push_str(&comment.source);
+                out.push('\n');
+            }
+            ServiceEntry::Option(option) => {
+                out.push_str(&indent(level + 1));
+                out.push_str(&render_option(option));
+                out.push('\n');
+            }
+            ServiceEntry::Rpc(rpc) => {
+                write_leading(out, &rpc.leading, level + 1);
+                out.push_str(&indent(level + 1));
+                out.push_str(&render_rpc(&rpc.inner));
+                write_trailing(out, &rpc.trailing);
+                out.push('\n');
+            }
+        }
+    }
+
+    out.push_str(&indent(level));
+    out.push('}');
+}
+
+fn render_rpc(rpc: &Rpc) -> String {
+    let (request_stream, reply_stream) = match rpc.stream {
+        RpcStream::None => ("", ""),
+        RpcStream::ServerBound => ("stream ", ""),
+        RpcStream::ClientBound => ("", "stream "),
+        RpcStream::Bidirectional => ("stream ", "stream "),
+    };
+
+    format!(
+        "rpc {}({}{}) returns ({}{});",
+        rpc.ident,
+        request_stream,
+        render_field_type(&rpc.request),
+        reply_stream,
+        render_field_type(&rpc.reply),
+    )
+}
+
+fn write_message(out: &mut String, message: &Message, level: usize) {
+    out.push_str(&format!("message {} {{\n", message.ident));
+
+    for entry in &message.entries {
+        write_message_entry(out, entry, level + 1);
+    }
+
+    out.push_str(&indent(level));
+    out.push('}');
+}
+
+fn write_message_entry(out: &mut String, entry: &MessageEntry, level: usize) {
+    match entry {
+        MessageEntry::Comment(comment) => {
+            out.push_str(&indent(level));
+            out.push_str(&comment.source);
+            out.push('\n');
+        }
+        MessageEntry::Option(option) => {
+            out.push_str(&indent(level));
+            out.push_str(&render_option(option));
+            out.push('\n');
+        }
+        MessageEntry::Field(field) => {
+            write_leading(out, &field.leading, level);
+            out.push_str(&indent(level));
+            out.push_str(&render_field(&field.inner));
+            write_trailing(out, &field.trailing);
+            out.push('\n');
+        }
+        MessageEntry::OneOf(one_of) => {
+            write_leading(out, &one_of.leading, level);
+            out.push_str(&indent(level));
+            write_one_of(out, &one_of.inner, level);
+            write_trailing(out, &one_of.trailing);
+            out.push('\n');
+        }
+        MessageEntry::Message(nested) => {
+            write_leading(out, &nested.leading, level);
+            out.push_str(&indent(level));
+            write_message(out, &nested.inner, level);
+            write_trailing(out, &nested.trailing);
+            out.push('\n');
+        }
+        MessageEntry::Extend(extend) => {
+            out.push_str(&indent(level));
+            write_extend(out, extend, level);
+            out.push('\n');
+        }
+        MessageEntry::Enum(r#enum) => {
+            write_leading(out, &r#enum.leading, level);
+            out.push_str(&indent(level));
+            write_enum(out, &r#enum.inner, level);
+            write_trailing(out, &r#enum.trailing);
+            out.push('\n');
+        }
+        MessageEntry::ReservedIndices(indices) => {
+            out.push_str(&indent(level));
+            out.push_str(&format!("reserved {};", render_ranges(indices)));
+            out.push('\n');
+        }
+        MessageEntry::ReservedIdents(idents) => {
+            let rendered = idents
+                .iter()
+                .map(|ident| format!("\"{ident}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            out.push_str(&indent(level));
+            out.push_str(&format!("reserved {rendered};"));
+            out.push('\n');
+        }
+        MessageEntry::Extensions(extensions) => {
+            out.push_str(&indent(level));
+            out.push_str(&format!("extensions {};", render_ranges(extensions)));
+            out.push('\n');
+        }
+    }
+}
+
+fn render_ranges(ranges: &[Range]) -> String {
+    ranges
+        .iter()
+        .map(render_range)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render_range(range: &Range) -> String {
+    match range {
+        Range::Default(range) if range.end - range.start == 1 => range.start.to_string(),
+        Range::Default(range) => format!("{} to {}", range.start, range.end - 1),
+        Range::From(range) => format!("{} to max", range.start),
+    }
+}
+
+fn write_one_of(out: &mut String, one_of: &OneOf, level: usize) {
+    out.push_str(&format!("oneof {} {{\n", one_of.ident));
+
+    for entry in &one_of.entries {
+        match entry {
+            OneOfEntry::Comment(comment) => {
+                out.push_str(&indent(level + 1));
+                out.push_str(&comment.source);
+                out.push('\n');
+            }
+            OneOfEntry::Option(option) => {
+                out.push_str(&indent(level + 1));
+                out.push_str(&render_option(option));
+                out.push('\n');
+            }
+            OneOfEntry::Field(field) => {
+                write_leading(out, &field.leading, level + 1);
+                out.push_str(&indent(level + 1));
+                out.push_str(&render_field(&field.inner));
+                write_trailing(out, &field.trailing);
+                out.push('\n');
+            }
+        }
+    }
+
+    out.push_str(&indent(level));
+    out.push('}');
+}
+
+fn write_extend(out: &mut String, extend: &Extend, level: usize) {
+    out.push_str(&format!("extend {} {{\n", extend.r#type));
+
+    for entry in &extend.entries {
+        match entry {
+            ExtendEntry::Comment(comment) => {
+                out.push_str(&indent(level + 1));
+                out.push_str(&comment.source);
+                out.push('\n');
+            }
+            ExtendEntry::Field(field) => {
+                write_leading(out, &field.leading, level + 1);
+                out.push_str(&indent(level + 1));
+                out.push_str(&render_field(&field.inner));
+                write_trailing(out, &field.trailing);
+                out.push('\n');
+            }
+        }
+    }
+
+    out.push_str(&indent(level));
+    out.push('}');
+}
+
+fn write_enum(out: &mut String, r#enum: &Enum, level: usize) {
+    out.push_str(&format!("enum {} {{\n", r#enum.ident));
+
+    for entry in &r#enum.entries {
+        match entry {
+            EnumEntry::Comment(comment) => {
+                out.push_str(&indent(level + 1));
+                out.push_str(&comment.source);
+                out.push('\n');
+            }
+            EnumEntry::Option(option) => {
+                out.push_str(&indent(level + 1));
+                out.push_str(&render_option(option));
+                out.push('\n');
+            }
+            EnumEntry::Variant(variant) => {
+                write_leading(out, &variant.leading, level + 1);
+                out.push_str(&indent(level + 1));
+
+                let options = render_inline_options(&variant.options);
+                out.push_str(&format!(
+                    "{} = {}{};",
+                    variant.ident, variant.value, options
+                ));
+
+                write_trailing(out, &variant.trailing);
+                out.push('\n');
+            }
+        }
+    }
+
+    out.push_str(&indent(level));
+    out.push('}');
+}
+
+fn render_field(field: &Field) -> String {
+    let modifier = match field.modifier {
+        Some(FieldModifier::Optional) => "optional ",
+        Some(FieldModifier::Required) => "required ",
+        Some(FieldModifier::Repeated) => "repeated ",
+        None => "",
+    };
+
+    format!(
+        "{modifier}{} {} = {}{};",
+        render_field_type(&field.r#type),
+        field.ident.value,
+        field.index.value,
+        render_inline_options(&field.options),
+    )
+}
+
+fn render_field_type(r#type: &FieldType) -> String {
+    match r#type {
+        FieldType::Double => "double".to_string(),
+        FieldType::Float => "float".to_string(),
+        FieldType::Int32 => "int32".to_string(),
+        FieldType::Int64 => "int64".to_string(),
+        FieldType::Uint32 => "uint32".to_string(),
+        FieldType::Uint64 => "uint64".to_string(),
+        FieldType::Sint32 => "sint32".to_string(),
+        FieldType::Sint64 => "sint64".to_string(),
+        FieldType::Fixed32 => "fixed32".to_string(),
+        FieldType::Fixed64 => "fixed64".to_string(),
+        FieldType::Sfixed32 => "sfixed32".to_string(),
+        FieldType::Sfixed64 => "sfixed64".to_string(),
+        FieldType::Bool => "bool".to_string(),
+        FieldType::String => "string".to_string(),
+        FieldType::Bytes => "bytes".to_string(),
+        FieldType::Map { key, value } => {
+            format!("map<{}, {}>", render_field_type(key), render_field_type(value))
+        }
+        FieldType::Named(name) => name.to_string(),
+    }
+}
+
+fn render_inline_options(options: &[AstOption]) -> String {
+    if options.is_empty() {
+        return String::new();
+    }
+
+    let rendered = options
+        .iter()
+        .map(|option| format!("{} = {}", option.key, render_map_value(&option.value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(" [{rendered}]")
+}
+
+fn render_option(option: &AstOption) -> String {
+    format!(
+        "option {} = {};",
+        option.key,
+        render_map_value(&option.value)
+    )
+}
+
+fn render_map_value(value: &MapValue) -> String {
+    match value {
+        MapValue::Boolean(value) => value.to_string(),
+        MapValue::Integer(value) => value.to_string(),
+        MapValue::Ident(ident) => ident.to_string(),
+        MapValue::String(value) => format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+        MapValue::Map(map) => render_map(map),
+    }
+}
+
+fn render_map(map: &Map) -> String {
+    let rendered = map
+        .iter()
+        .map(|(key, value)| format!("{key}: {}", render_map_value(value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("{{ {rendered} }}")
+}
@@ -7,6 +7,8 @@ lalrpop_mod!(
 );
 
 pub mod ast;
+pub mod diagnostic;
+pub mod display;
 pub mod lexer;
 
 #[cfg(test)]
@@ -22,3 +24,55 @@ pub fn parse<'a>(
 
     parser.parse(data, lexer)
 }
+
+/// Parses a single top-level declaration starting at byte `offset` and
+/// returns it together with the offset of the first byte not consumed.
+///
+/// This lets callers feed a growing buffer (e.g. a `.proto` read off a
+/// socket or stdin) and process declarations as they complete, or let an
+/// editor re-parse only the region touched by an edit instead of the whole
+/// file. Repeatedly calling this with the returned offset until it reaches
+/// `data.len()` is equivalent to [`parse`].
+#[allow(clippy::needless_lifetimes)]
+pub fn parse_entry<'a>(
+    data: &'a str,
+    offset: usize,
+) -> Result<
+    (ast::RootEntry<'a>, usize),
+    lalrpop_util::ParseError<usize, lexer::Token<'a>, lexer::LexicalError<'a>>,
+> {
+    let lexer = lexer::Lexer::new_at(data, offset);
+    let parser = proto::FileEntryParser::new();
+
+    parser.parse(data, lexer)
+}
+
+/// Parses `data` and serializes the resulting AST to a JSON string, giving
+/// callers a portable intermediate representation of a `.proto` file without
+/// re-implementing protoc's descriptor format.
+#[cfg(feature = "serde")]
+pub fn parse_to_json(data: &str) -> Result<String, ParseToJsonError<'_>> {
+    let ast = parse(data).map_err(ParseToJsonError::Parse)?;
+    serde_json::to_string(&ast).map_err(ParseToJsonError::Serialize)
+}
+
+/// Error returned by [`parse_to_json`].
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum ParseToJsonError<'a> {
+    Parse(lalrpop_util::ParseError<usize, lexer::Token<'a>, lexer::LexicalError<'a>>),
+    Serialize(serde_json::Error),
+}
+
+#[cfg(feature = "serde")]
+impl<'a> std::fmt::Display for ParseToJsonError<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "{err}"),
+            Self::Serialize(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> std::error::Error for ParseToJsonError<'a> {}
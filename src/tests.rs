@@ -194,7 +194,7 @@ fn options() {
             "Enum",
             vec![
                 ast::EnumEntry::from(ast::Option::new("allow_alias", ast::MapValue::from(true))),
-                ast::EnumEntry::Variant(ast::EnumVariant::new(
+                ast::EnumEntry::from(ast::EnumVariant::new(
                     "FIRST",
                     0,
                     vec![ast::Option::new("deprecated", ast::MapValue::from(true))],
@@ -460,3 +460,143 @@ fn service() {
 
     assert_eq!(ast, target_ast);
 }
+
+#[test]
+fn documented_doc_comments_and_trivia() {
+    let field = ast::Field::new(None, "bool", "flag", 1, vec![]);
+    let documented = ast::Documented::new(field, vec![ast::Comment::single_line("// a flag")], None)
+        .with_trivia(Some(ast::Span::new(0, 1)), None);
+
+    assert_eq!(
+        documented.doc_comments().collect::<Vec<_>>(),
+        vec!["a flag"]
+    );
+    assert_eq!(documented.leading_trivia, Some(ast::Span::new(0, 1)));
+    assert_eq!(documented.trailing_trivia, None);
+}
+
+#[test]
+fn field_with_spans_resolves_to_real_source_positions() {
+    let source = "message M {\n    string name = 1;\n}";
+    let field_span = ast::Span::new(16, 32);
+    let ident_span = ast::Span::new(23, 27);
+    let index_span = ast::Span::new(30, 31);
+    let field = ast::Field::with_spans(
+        None,
+        "string",
+        ("name", ident_span),
+        (1, index_span),
+        vec![],
+        field_span,
+    );
+
+    assert_eq!(field.ident.span, ident_span);
+    assert_eq!(field.index.span, index_span);
+    assert_eq!(field.ident.span.resolve(source), (2, 12));
+    assert_eq!(field.index.span.resolve(source), (2, 19));
+}
+
+mod display {
+    use super::parse_ast;
+    use crate::ast;
+    use crate::display::ToProtoString;
+    use crate::parse;
+
+    fn assert_round_trips(ast: crate::ast::Root<'_>) {
+        let printed = ast.to_proto_string();
+        let reparsed = match parse(&printed) {
+            Err(error) => panic!("{error}\n--- printed ---\n{printed}"),
+            Ok(ast) => ast,
+        };
+
+        assert_eq!(ast, reparsed);
+    }
+
+    #[test]
+    fn message_round_trip() {
+        assert_round_trips(parse_ast!("message.proto"));
+    }
+
+    #[test]
+    fn options_round_trip() {
+        assert_round_trips(parse_ast!("options.proto"));
+    }
+
+    #[test]
+    fn service_round_trip() {
+        assert_round_trips(parse_ast!("service.proto"));
+    }
+
+    #[test]
+    fn extensions_round_trip() {
+        assert_round_trips(parse_ast!("extensions.proto"));
+    }
+
+    #[test]
+    fn enum_round_trip() {
+        assert_round_trips(parse_ast!("enum.proto"));
+    }
+
+    #[test]
+    fn oneof_round_trip() {
+        assert_round_trips(parse_ast!("oneof.proto"));
+    }
+
+    #[test]
+    fn to_proto_honors_starting_indent() {
+        let ast = parse_ast!("message-empty.proto");
+        let printed = ast.to_proto(1);
+
+        assert!(printed.starts_with("    message"));
+    }
+
+    #[test]
+    fn to_proto_indents_commented_declarations_at_nonzero_indent() {
+        let message = ast::Documented::new(
+            ast::Message::empty("Empty"),
+            vec![ast::Comment::single_line("// a message")],
+            None,
+        );
+        let ast = vec![ast::RootEntry::from(message)];
+
+        let printed = ast.to_proto(1);
+
+        assert_eq!(printed, "    // a message\n    message Empty {\n    }\n");
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::parse_ast;
+
+    fn assert_round_trips(ast: crate::ast::Root<'_>) {
+        let json = serde_json::to_string(&ast).expect("serialize to json");
+        let roundtripped = serde_json::from_str(&json).expect("deserialize from json");
+
+        assert_eq!(ast, roundtripped);
+    }
+
+    #[test]
+    fn options_round_trip() {
+        assert_round_trips(parse_ast!("options.proto"));
+    }
+
+    #[test]
+    fn message_round_trip() {
+        assert_round_trips(parse_ast!("message.proto"));
+    }
+
+    #[test]
+    fn extensions_round_trip() {
+        assert_round_trips(parse_ast!("extensions.proto"));
+    }
+
+    #[test]
+    fn parse_to_json_matches_serialized_ast() {
+        let data = include_str!("../proto/tests/extensions.proto");
+        let ast = parse_ast!("extensions.proto");
+
+        let json = crate::parse_to_json(data).expect("parse to json");
+        assert_eq!(json, serde_json::to_string(&ast).expect("serialize to json"));
+    }
+}